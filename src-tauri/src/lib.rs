@@ -1,15 +1,35 @@
 use tauri::{AppHandle, Manager, WebviewWindow};
 use tauri_plugin_global_shortcut::GlobalShortcutExt;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 #[cfg(target_os = "macos")]
-use cocoa::base::id;
+use cocoa::base::{id, nil};
 #[cfg(target_os = "macos")]
-use objc::{msg_send, sel, sel_impl};
+use cocoa::appkit::{NSWindow, NSWindowButton};
+#[cfg(target_os = "macos")]
+use objc::{class, msg_send, sel, sel_impl};
+#[cfg(target_os = "macos")]
+use objc::declare::ClassDecl;
+#[cfg(target_os = "macos")]
+use objc::runtime::{Class, Object, Sel};
+#[cfg(target_os = "macos")]
+use tauri::Emitter;
 #[cfg(target_os = "macos")]
 use tauri_nspanel::{WebviewWindowExt, cocoa::appkit::NSWindowCollectionBehavior};
 
+// AppKit-exported notification name constant, reused across set_auto_dim calls instead of
+// allocating a fresh NSString each time
+#[cfg(target_os = "macos")]
+#[link(name = "AppKit", kind = "framework")]
+extern "C" {
+    static NSViewFrameDidChangeNotification: id;
+}
+
+// Default traffic-light inset applied when the custom titlebar is configured at startup
+const DEFAULT_TITLEBAR_INSET_X: f64 = 12.0;
+const DEFAULT_TITLEBAR_INSET_Y: f64 = 12.0;
+
 // macOS-specific window configuration for fullscreen overlay
 #[cfg(target_os = "macos")]
 #[tauri::command]
@@ -71,6 +91,459 @@ async fn ensure_window_top_level(window: WebviewWindow) -> Result<(), String> {
     Ok(())
 }
 
+// Caches each traffic-light button's un-inset position the first time configure_titlebar runs,
+// so repeat calls (e.g. a user-adjustable inset setting) recompute the offset from a stable
+// baseline instead of compounding insets on top of an already-shifted position.
+#[derive(Default)]
+struct TitlebarState {
+    #[cfg(target_os = "macos")]
+    button_baseline: Mutex<Option<[(f64, f64); 3]>>,
+}
+
+// Hides the native title bar while keeping the traffic-light buttons visible and draggable,
+// repositioning them by the given inset so the frontend can render a thin custom drag region.
+#[cfg(target_os = "macos")]
+#[tauri::command]
+async fn configure_titlebar(window: WebviewWindow, inset_x: f64, inset_y: f64) -> Result<(), String> {
+    let state = window.state::<TitlebarState>();
+
+    window.with_webview(move |webview| {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let ns_window = webview.ns_window() as id;
+
+            let _: () = msg_send![ns_window, setTitlebarAppearsTransparent: true];
+            let _: () = msg_send![ns_window, setTitleVisibility: 1i64]; // NSWindowTitleHidden
+
+            // Restore the title bar bits the NSPanel setup stripped down to just
+            // NSNonactivatingPanelMask, since standardWindowButton_ returns nil for every
+            // button without NSWindowStyleMaskTitled
+            #[allow(non_upper_case_globals)]
+            const NSWindowStyleMaskTitled: u64 = 1 << 0;
+            #[allow(non_upper_case_globals)]
+            const NSWindowStyleMaskClosable: u64 = 1 << 1;
+            #[allow(non_upper_case_globals)]
+            const NSWindowStyleMaskMiniaturizable: u64 = 1 << 2;
+            #[allow(non_upper_case_globals)]
+            const NSWindowStyleMaskFullSizeContentView: u64 = 1 << 15;
+            let style_mask: u64 = msg_send![ns_window, styleMask];
+            let updated_mask = style_mask
+                | NSWindowStyleMaskTitled
+                | NSWindowStyleMaskClosable
+                | NSWindowStyleMaskMiniaturizable
+                | NSWindowStyleMaskFullSizeContentView;
+            let _: () = msg_send![ns_window, setStyleMask: updated_mask];
+
+            let button_types = [
+                NSWindowButton::NSWindowCloseButton,
+                NSWindowButton::NSWindowMiniaturizeButton,
+                NSWindowButton::NSWindowZoomButton,
+            ];
+
+            let mut baseline_guard = state.button_baseline.lock().expect("titlebar state poisoned");
+            let baseline = *baseline_guard.get_or_insert_with(|| {
+                let mut origins = [(0.0, 0.0); 3];
+                for (i, button_type) in button_types.into_iter().enumerate() {
+                    let button: id = ns_window.standardWindowButton_(button_type);
+                    if button != nil {
+                        let frame: cocoa::foundation::NSRect = msg_send![button, frame];
+                        origins[i] = (frame.origin.x, frame.origin.y);
+                    }
+                }
+                origins
+            });
+            drop(baseline_guard);
+
+            for (i, button_type) in button_types.into_iter().enumerate() {
+                let button: id = ns_window.standardWindowButton_(button_type);
+                if button != nil {
+                    let (base_x, base_y) = baseline[i];
+                    let new_origin = cocoa::foundation::NSPoint::new(base_x + inset_x, base_y - inset_y);
+                    let _: () = msg_send![button, setFrameOrigin: new_origin];
+                }
+            }
+
+            println!("Configured custom titlebar with traffic-light inset ({}, {})", inset_x, inset_y);
+        }
+    }).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+// Non-macOS fallback: there's no native titlebar chrome to reposition
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+async fn configure_titlebar(_window: WebviewWindow, _inset_x: f64, _inset_y: f64) -> Result<(), String> {
+    Ok(())
+}
+
+// Tracks the frame to restore when "simple fullscreen" (Alacritty-style, no new Space) toggles off
+#[derive(Default)]
+struct OverlayFullscreenState {
+    #[cfg(target_os = "macos")]
+    simple_fullscreen: Mutex<Option<SimpleFullscreenFrame>>,
+}
+
+#[cfg(target_os = "macos")]
+#[derive(Clone, Copy)]
+struct SimpleFullscreenFrame {
+    origin_x: f64,
+    origin_y: f64,
+    width: f64,
+    height: f64,
+    style_mask: u64,
+}
+
+// Native macOS fullscreen: hands off to a dedicated Space via toggleFullScreen:
+#[tauri::command]
+async fn toggle_fullscreen(window: WebviewWindow) -> Result<bool, String> {
+    let is_fullscreen = window.is_fullscreen().map_err(|e| e.to_string())?;
+    window.set_fullscreen(!is_fullscreen).map_err(|e| e.to_string())?;
+    Ok(!is_fullscreen)
+}
+
+// "Simple fullscreen": resizes the panel to cover the active NSScreen's full frame without
+// creating a new Space, so the overlay keeps NSWindowCollectionBehaviorCanJoinAllSpaces and
+// stays layered over other apps' fullscreen windows.
+#[cfg(target_os = "macos")]
+#[tauri::command]
+async fn toggle_simple_fullscreen(window: WebviewWindow) -> Result<bool, String> {
+    let state = window.state::<OverlayFullscreenState>();
+    let saved_frame = { state.simple_fullscreen.lock().map_err(|_| "fullscreen state poisoned".to_string())?.take() };
+
+    if let Some(frame) = saved_frame {
+        window.with_webview(move |webview| {
+            unsafe {
+                let ns_window = webview.ns_window() as id;
+                let rect = cocoa::foundation::NSRect::new(
+                    cocoa::foundation::NSPoint::new(frame.origin_x, frame.origin_y),
+                    cocoa::foundation::NSSize::new(frame.width, frame.height),
+                );
+                let _: () = msg_send![ns_window, setStyleMask: frame.style_mask];
+                let _: () = msg_send![ns_window, setFrame:rect display:true];
+            }
+        }).map_err(|e| e.to_string())?;
+
+        Ok(false)
+    } else {
+        let captured = window.with_webview(|webview| {
+            unsafe {
+                let ns_window = webview.ns_window() as id;
+
+                let style_mask: u64 = msg_send![ns_window, styleMask];
+                let current_frame: cocoa::foundation::NSRect = msg_send![ns_window, frame];
+                let screen: id = msg_send![ns_window, screen];
+                let screen_frame: cocoa::foundation::NSRect = msg_send![screen, frame];
+
+                #[allow(non_upper_case_globals)]
+                const NSWindowStyleMaskTitled: u64 = 1 << 0;
+                let _: () = msg_send![ns_window, setStyleMask: style_mask & !NSWindowStyleMaskTitled];
+                let _: () = msg_send![ns_window, setFrame:screen_frame display:true];
+
+                SimpleFullscreenFrame {
+                    origin_x: current_frame.origin.x,
+                    origin_y: current_frame.origin.y,
+                    width: current_frame.size.width,
+                    height: current_frame.size.height,
+                    style_mask,
+                }
+            }
+        }).map_err(|e| e.to_string())?;
+
+        *state.simple_fullscreen.lock().map_err(|_| "fullscreen state poisoned".to_string())? = Some(captured);
+        Ok(true)
+    }
+}
+
+// Non-macOS fallback: there's no separate "Space" concept, so simple fullscreen just maps to
+// the regular fullscreen toggle
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+async fn toggle_simple_fullscreen(window: WebviewWindow) -> Result<bool, String> {
+    toggle_fullscreen(window).await
+}
+
+// Reports which fullscreen mode (if any) the overlay is currently in, so the frontend can
+// adjust its layout accordingly
+#[tauri::command]
+async fn get_fullscreen_mode(window: WebviewWindow) -> Result<String, String> {
+    if window.is_fullscreen().map_err(|e| e.to_string())? {
+        return Ok("native".to_string());
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let state = window.state::<OverlayFullscreenState>();
+        let saved = state.simple_fullscreen.lock().map_err(|_| "fullscreen state poisoned".to_string())?;
+        if saved.is_some() {
+            return Ok("simple".to_string());
+        }
+    }
+
+    Ok("none".to_string())
+}
+
+// Maps the caller-chosen material name to the matching NSVisualEffectMaterial raw value
+#[cfg(target_os = "macos")]
+fn resolve_vibrancy_material(material: &str) -> i64 {
+    match material {
+        "titlebar" => 3,
+        "selection" => 4,
+        "menu" => 5,
+        "popover" => 6,
+        "sidebar" => 7,
+        "header-view" => 10,
+        "sheet" => 11,
+        "window-background" => 12,
+        "hud-window" | "hud" => 13,
+        "fullscreen-ui" => 15,
+        "tooltip" => 17,
+        "content-background" => 18,
+        "under-window-background" => 21,
+        "under-page-background" => 22,
+        _ => 13, // default to HUD, matching the frosted overlay look used elsewhere
+    }
+}
+
+// Tracks the currently-installed NSVisualEffectView so a later set_window_vibrancy call can
+// tear down the previous one instead of stacking views behind the webview forever
+#[derive(Default)]
+struct VibrancyState {
+    #[cfg(target_os = "macos")]
+    effect_view: Mutex<Option<id>>,
+}
+
+#[cfg(target_os = "macos")]
+unsafe impl Send for VibrancyState {}
+#[cfg(target_os = "macos")]
+unsafe impl Sync for VibrancyState {}
+
+// Renders the note overlay over a blurred, frosted NSVisualEffectView instead of an opaque fill,
+// matching the frosted look of native macOS panels. Fits alongside the existing
+// ensure_window_top_level NSWindow manipulation.
+#[cfg(target_os = "macos")]
+#[tauri::command]
+async fn set_window_vibrancy(window: WebviewWindow, material: String) -> Result<(), String> {
+    let state = window.state::<VibrancyState>();
+
+    window.with_webview(move |webview| {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let ns_window = webview.ns_window() as id;
+            let content_view: id = msg_send![ns_window, contentView];
+            let bounds: cocoa::foundation::NSRect = msg_send![content_view, bounds];
+
+            let effect_view: id = msg_send![objc::class!(NSVisualEffectView), alloc];
+            let effect_view: id = msg_send![effect_view, initWithFrame: bounds];
+
+            #[allow(non_upper_case_globals)]
+            const NSVisualEffectBlendingModeBehindWindow: i64 = 0;
+            #[allow(non_upper_case_globals)]
+            const NSVisualEffectStateActive: i64 = 1;
+            let _: () = msg_send![effect_view, setBlendingMode: NSVisualEffectBlendingModeBehindWindow];
+            let _: () = msg_send![effect_view, setState: NSVisualEffectStateActive];
+            let _: () = msg_send![effect_view, setMaterial: resolve_vibrancy_material(&material)];
+
+            // NSViewWidthSizable | NSViewHeightSizable, so the blur tracks live resizes
+            let _: () = msg_send![effect_view, setAutoresizingMask: 18u64];
+
+            let _: () = msg_send![content_view, addSubview:effect_view positioned:-1i64 relativeTo:nil];
+
+            let _: () = msg_send![ns_window, setOpaque: false];
+            let clear_color: id = msg_send![objc::class!(NSColor), clearColor];
+            let _: () = msg_send![ns_window, setBackgroundColor: clear_color];
+
+            // Remove and release whichever effect view we installed previously, now that the
+            // new one is in place, so repeated calls don't stack views or leak them
+            let previous = state.effect_view.lock()
+                .map(|mut guard| guard.replace(effect_view))
+                .unwrap_or(None);
+            if let Some(previous_view) = previous {
+                let _: () = msg_send![previous_view, removeFromSuperview];
+                let _: () = msg_send![previous_view, release];
+            }
+
+            println!("Applied window vibrancy with material '{}'", material);
+        }
+    }).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+// Non-macOS fallback: no NSVisualEffectView equivalent wired up yet
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+async fn set_window_vibrancy(_window: WebviewWindow, _material: String) -> Result<(), String> {
+    Err("Window vibrancy is only supported on macOS".to_string())
+}
+
+// Tracks the hover observer so a later set_auto_dim call can tear down the previous one
+// once the new tracking area and observer are installed
+#[cfg(target_os = "macos")]
+struct AutoDimObserver {
+    observer_object: id,
+    app_handle_box: *mut AppHandle,
+}
+
+#[cfg(target_os = "macos")]
+unsafe impl Send for AutoDimObserver {}
+#[cfg(target_os = "macos")]
+unsafe impl Sync for AutoDimObserver {}
+
+#[derive(Default)]
+struct AutoDimState {
+    #[cfg(target_os = "macos")]
+    observer: Mutex<Option<AutoDimObserver>>,
+}
+
+// Lazily declares the NotesOverlayHoverObserver Objective-C class used as the NSTrackingArea's
+// owner: it receives mouseEntered:/mouseExited: and forwards them into alpha changes + Tauri
+// events, and rebuildTrackingArea: re-installs the tracking area when the content view resizes.
+#[cfg(target_os = "macos")]
+fn auto_dim_observer_class() -> &'static Class {
+    use std::sync::Once;
+
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        let superclass = class!(NSObject);
+        let mut decl = ClassDecl::new("NotesOverlayHoverObserver", superclass)
+            .expect("NotesOverlayHoverObserver already registered");
+
+        decl.add_ivar::<*mut std::ffi::c_void>("_appHandle");
+        decl.add_ivar::<id>("_nsWindow");
+        decl.add_ivar::<f64>("_idleAlpha");
+
+        extern "C" fn mouse_entered(this: &Object, _sel: Sel, _event: id) {
+            unsafe {
+                let ns_window: id = *this.get_ivar("_nsWindow");
+                let _: () = msg_send![ns_window, setAlphaValue: 1.0f64];
+                emit_hover_event(this, "overlay-hover-enter");
+            }
+        }
+
+        extern "C" fn mouse_exited(this: &Object, _sel: Sel, _event: id) {
+            unsafe {
+                let ns_window: id = *this.get_ivar("_nsWindow");
+                let idle_alpha: f64 = *this.get_ivar("_idleAlpha");
+                // Animate the fade-out rather than snapping straight to the idle alpha
+                let animator: id = msg_send![ns_window, animator];
+                let _: () = msg_send![animator, setAlphaValue: idle_alpha];
+                emit_hover_event(this, "overlay-hover-exit");
+            }
+        }
+
+        extern "C" fn rebuild_tracking_area(this: &Object, _sel: Sel, _notification: id) {
+            unsafe {
+                let ns_window: id = *this.get_ivar("_nsWindow");
+                let content_view: id = msg_send![ns_window, contentView];
+                install_tracking_area(content_view, this as *const Object as id);
+            }
+        }
+
+        unsafe {
+            decl.add_method(sel!(mouseEntered:), mouse_entered as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(mouseExited:), mouse_exited as extern "C" fn(&Object, Sel, id));
+            decl.add_method(sel!(rebuildTrackingArea:), rebuild_tracking_area as extern "C" fn(&Object, Sel, id));
+        }
+
+        decl.register();
+    });
+
+    Class::get("NotesOverlayHoverObserver").expect("NotesOverlayHoverObserver registered above")
+}
+
+#[cfg(target_os = "macos")]
+unsafe fn emit_hover_event(this: &Object, event: &str) {
+    let app_handle_ptr: *mut std::ffi::c_void = *this.get_ivar("_appHandle");
+    if !app_handle_ptr.is_null() {
+        let app_handle = &*(app_handle_ptr as *const AppHandle);
+        let _ = app_handle.emit(event, ());
+    }
+}
+
+// (Re)installs an NSTrackingArea covering the view's bounds, tied to NSTrackingInVisibleRect so
+// it stays in sync with live resizes, and removes whatever tracking areas were there before.
+#[cfg(target_os = "macos")]
+unsafe fn install_tracking_area(content_view: id, owner: id) {
+    let existing_areas: id = msg_send![content_view, trackingAreas];
+    let existing_count: usize = msg_send![existing_areas, count];
+    for i in (0..existing_count).rev() {
+        let area: id = msg_send![existing_areas, objectAtIndex: i];
+        let _: () = msg_send![content_view, removeTrackingArea: area];
+    }
+
+    let bounds: cocoa::foundation::NSRect = msg_send![content_view, bounds];
+
+    #[allow(non_upper_case_globals)]
+    const NSTrackingMouseEnteredAndExited: u64 = 0x01;
+    #[allow(non_upper_case_globals)]
+    const NSTrackingActiveAlways: u64 = 0x80;
+    #[allow(non_upper_case_globals)]
+    const NSTrackingInVisibleRect: u64 = 0x200;
+    let options = NSTrackingMouseEnteredAndExited | NSTrackingActiveAlways | NSTrackingInVisibleRect;
+
+    let tracking_area: id = msg_send![class!(NSTrackingArea), alloc];
+    let tracking_area: id = msg_send![tracking_area, initWithRect:bounds options:options owner:owner userInfo:nil];
+    let _: () = msg_send![content_view, addTrackingArea: tracking_area];
+}
+
+// Makes the overlay fade to `idle_alpha` while the pointer isn't over it, returning to full
+// opacity on hover. Also emits `overlay-hover-enter` / `overlay-hover-exit` so the frontend can
+// react. Rebuilds the tracking area on resize since it's tied to the content view's bounds.
+#[cfg(target_os = "macos")]
+#[tauri::command]
+async fn set_auto_dim(window: WebviewWindow, app_handle: AppHandle, idle_alpha: f64) -> Result<(), String> {
+    let state = window.state::<AutoDimState>();
+
+    window.with_webview(move |webview| {
+        unsafe {
+            let ns_window = webview.ns_window() as id;
+            let content_view: id = msg_send![ns_window, contentView];
+
+            let class = auto_dim_observer_class();
+            let observer: id = msg_send![class, alloc];
+            let observer: id = msg_send![observer, init];
+
+            let app_handle_box = Box::into_raw(Box::new(app_handle.clone()));
+            let observer_obj: &mut Object = &mut *(observer as *mut Object);
+            observer_obj.set_ivar("_appHandle", app_handle_box as *mut std::ffi::c_void);
+            observer_obj.set_ivar("_nsWindow", ns_window);
+            observer_obj.set_ivar("_idleAlpha", idle_alpha);
+
+            install_tracking_area(content_view, observer);
+
+            let _: () = msg_send![content_view, setPostsFrameChangedNotifications: true];
+            let notification_center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+            let _: () = msg_send![
+                notification_center,
+                addObserver: observer
+                selector: sel!(rebuildTrackingArea:)
+                name: NSViewFrameDidChangeNotification
+                object: content_view
+            ];
+
+            let previous = state.observer.lock()
+                .map(|mut guard| guard.replace(AutoDimObserver { observer_object: observer, app_handle_box }))
+                .unwrap_or(None);
+
+            if let Some(prev) = previous {
+                let _: () = msg_send![notification_center, removeObserver: prev.observer_object];
+                let _: () = msg_send![prev.observer_object, release];
+                drop(Box::from_raw(prev.app_handle_box));
+            }
+        }
+    }).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+// Non-macOS fallback: no NSTrackingArea equivalent wired up yet
+#[cfg(not(target_os = "macos"))]
+#[tauri::command]
+async fn set_auto_dim(_window: WebviewWindow, _app_handle: AppHandle, _idle_alpha: f64) -> Result<(), String> {
+    Err("Auto-dim is only supported on macOS".to_string())
+}
+
 #[tauri::command]
 async fn save_note(app_handle: AppHandle, content: String) -> Result<(), String> {
     let app_data_dir = app_handle.path().app_data_dir()
@@ -101,6 +574,152 @@ async fn load_note(app_handle: AppHandle) -> Result<String, String> {
     }
 }
 
+fn content_protection_file(app_handle: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let app_data_dir = app_handle.path().app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+
+    std::fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+
+    Ok(app_data_dir.join("content_protection.txt"))
+}
+
+fn persist_content_protection(app_handle: &AppHandle, enabled: bool) -> Result<(), String> {
+    let path = content_protection_file(app_handle)?;
+    std::fs::write(path, if enabled { "true" } else { "false" })
+        .map_err(|e| format!("Failed to save content protection setting: {}", e))
+}
+
+fn load_content_protection(app_handle: &AppHandle) -> bool {
+    let Ok(app_data_dir) = app_handle.path().app_data_dir() else {
+        return false;
+    };
+
+    std::fs::read_to_string(app_data_dir.join("content_protection.txt"))
+        .map(|contents| contents.trim() == "true")
+        .unwrap_or(false)
+}
+
+// Excludes the overlay from screen recordings and shared-screen streams so it stays private
+// while the user is presenting. Persists the preference so it survives restarts.
+#[tauri::command]
+async fn set_content_protection(window: WebviewWindow, app_handle: AppHandle, enabled: bool) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    window.with_webview(move |webview| {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let ns_window = webview.ns_window() as id;
+
+            #[allow(non_upper_case_globals)]
+            const NSWindowSharingNone: u64 = 0;
+            #[allow(non_upper_case_globals)]
+            const NSWindowSharingReadOnly: u64 = 1;
+            let sharing_type = if enabled { NSWindowSharingNone } else { NSWindowSharingReadOnly };
+            let _: () = msg_send![ns_window, setSharingType: sharing_type];
+        }
+    }).map_err(|e| e.to_string())?;
+
+    // Content protection has no non-macOS equivalent yet; fall back gracefully
+    #[cfg(not(target_os = "macos"))]
+    let _ = &window;
+
+    persist_content_protection(&app_handle, enabled)
+}
+
+const DEFAULT_TOGGLE_SHORTCUT: &str = "Cmd+Shift+`";
+
+// Tracks the debouncer shared by whichever accelerator is currently registered, plus the
+// accelerator itself, so set_toggle_shortcut can unregister the old one and register the new one
+struct ToggleShortcutState {
+    debouncer: Arc<AtomicBool>,
+    current_accelerator: Mutex<String>,
+}
+
+impl Default for ToggleShortcutState {
+    fn default() -> Self {
+        Self {
+            debouncer: Arc::new(AtomicBool::new(false)),
+            current_accelerator: Mutex::new(DEFAULT_TOGGLE_SHORTCUT.to_string()),
+        }
+    }
+}
+
+fn toggle_shortcut_file(app_handle: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let app_data_dir = app_handle.path().app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+
+    std::fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+
+    Ok(app_data_dir.join("toggle_shortcut.txt"))
+}
+
+fn persist_toggle_shortcut(app_handle: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let path = toggle_shortcut_file(app_handle)?;
+    std::fs::write(path, accelerator)
+        .map_err(|e| format!("Failed to save toggle shortcut: {}", e))
+}
+
+fn load_toggle_shortcut(app_handle: &AppHandle) -> String {
+    let Ok(app_data_dir) = app_handle.path().app_data_dir() else {
+        return DEFAULT_TOGGLE_SHORTCUT.to_string();
+    };
+
+    std::fs::read_to_string(app_data_dir.join("toggle_shortcut.txt"))
+        .ok()
+        .map(|contents| contents.trim().to_string())
+        .filter(|accelerator| !accelerator.is_empty())
+        .unwrap_or_else(|| DEFAULT_TOGGLE_SHORTCUT.to_string())
+}
+
+// Registers `accelerator` as the global toggle shortcut, reusing the debouncing AtomicBool
+// so rapid repeated triggers still collapse into a single toggle_window call
+fn register_toggle_shortcut(app_handle: &AppHandle, window: WebviewWindow, accelerator: &str) -> Result<(), String> {
+    let state = app_handle.state::<ToggleShortcutState>();
+    let debouncer = state.debouncer.clone();
+
+    app_handle.global_shortcut().on_shortcut(accelerator, move |_, _, _| {
+        if debouncer.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
+            let window = window.clone();
+            let debouncer = debouncer.clone();
+
+            tauri::async_runtime::spawn(async move {
+                let _ = toggle_window(window).await;
+                tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+                debouncer.store(false, Ordering::SeqCst);
+            });
+        }
+    }).map_err(|e| format!("Shortcut '{}' is already taken or invalid: {}", accelerator, e))
+}
+
+// Lets the user rebind the overlay toggle to whatever keystroke they prefer without
+// recompiling: unregisters the previous accelerator, validates and registers the new one,
+// and persists it so it survives restarts.
+#[tauri::command]
+async fn set_toggle_shortcut(app_handle: AppHandle, window: WebviewWindow, accelerator: String) -> Result<(), String> {
+    let previous_accelerator = {
+        let state = app_handle.state::<ToggleShortcutState>();
+        state.current_accelerator.lock().map_err(|_| "toggle shortcut state poisoned".to_string())?.clone()
+    };
+
+    app_handle.global_shortcut().unregister(previous_accelerator.as_str())
+        .map_err(|e| format!("Failed to unregister previous shortcut '{}': {}", previous_accelerator, e))?;
+
+    if let Err(e) = register_toggle_shortcut(&app_handle, window.clone(), &accelerator) {
+        // Put the previous shortcut back so the overlay isn't left with no toggle at all
+        let _ = register_toggle_shortcut(&app_handle, window, &previous_accelerator);
+        return Err(e);
+    }
+
+    {
+        let state = app_handle.state::<ToggleShortcutState>();
+        *state.current_accelerator.lock().map_err(|_| "toggle shortcut state poisoned".to_string())? = accelerator.clone();
+    }
+    persist_toggle_shortcut(&app_handle, &accelerator)?;
+
+    Ok(())
+}
+
 #[tauri::command]
 async fn toggle_window(window: WebviewWindow) -> Result<(), String> {
     let is_visible = window.is_visible().map_err(|e| e.to_string())?;
@@ -120,7 +739,13 @@ async fn toggle_window(window: WebviewWindow) -> Result<(), String> {
         // Note: Removed set_focus() call to preserve non-activating behavior for fullscreen overlay
         window.set_always_on_top(true).map_err(|e| e.to_string())?;
         window.set_visible_on_all_workspaces(true).map_err(|e| e.to_string())?;
-        
+
+        // Re-run positioning in case the active monitor (the one under the cursor) changed
+        // since the overlay was last shown
+        if let Err(e) = position_window_top_right(window.clone()).await {
+            eprintln!("Failed to reposition window on show: {}", e);
+        }
+
         // CRITICAL: Re-apply the top-level window settings aggressively when showing
         for i in 0..3 {
             tokio::time::sleep(tokio::time::Duration::from_millis(25)).await;
@@ -183,28 +808,82 @@ async fn debug_window_info(window: WebviewWindow) -> Result<String, String> {
     Ok(debug_info)
 }
 
+// Finds the monitor whose bounds contain the given physical point, falling back to the
+// primary monitor if the point lands between displays (e.g. right after a monitor is unplugged)
+fn monitor_at_point(window: &WebviewWindow, point: tauri::PhysicalPosition<f64>) -> Result<tauri::Monitor, String> {
+    let monitors = window.available_monitors().map_err(|e| e.to_string())?;
+
+    let containing = monitors.into_iter().find(|monitor| {
+        let position = monitor.position();
+        let size = monitor.size();
+        point.x >= position.x as f64
+            && point.x < (position.x + size.width as i32) as f64
+            && point.y >= position.y as f64
+            && point.y < (position.y + size.height as i32) as f64
+    });
+
+    if let Some(monitor) = containing {
+        return Ok(monitor);
+    }
+
+    window.primary_monitor()
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "No monitor found".to_string())
+}
+
+// Positions the window relative to the monitor currently under the cursor, anchored to one of
+// its corners (or centered). Accounts for the monitor's position offset and scale factor so
+// multi-display setups land the overlay on the right screen.
 #[tauri::command]
-async fn position_window_top_right(window: WebviewWindow) -> Result<(), String> {
+async fn position_window(window: WebviewWindow, anchor: String) -> Result<(), String> {
     use tauri::PhysicalPosition;
-    
-    // Get the primary monitor size
-    let monitor = window.primary_monitor()
-        .map_err(|e| e.to_string())?
-        .ok_or("No primary monitor found")?;
-    
+
+    let cursor = window.cursor_position().map_err(|e| e.to_string())?;
+    let monitor = monitor_at_point(&window, cursor)?;
+
+    let monitor_position = monitor.position();
     let monitor_size = monitor.size();
+    let scale_factor = monitor.scale_factor();
     let window_size = window.outer_size().map_err(|e| e.to_string())?;
-    
-    // Position in top-right corner with some padding
-    let x = (monitor_size.width as i32) - (window_size.width as i32) - 20;
-    let y = 40;
-    
+
+    let padding = (20.0 * scale_factor).round() as i32;
+    let top_padding = (40.0 * scale_factor).round() as i32;
+
+    let (x, y) = match anchor.as_str() {
+        "top-left" => (
+            monitor_position.x + padding,
+            monitor_position.y + top_padding,
+        ),
+        "bottom-left" => (
+            monitor_position.x + padding,
+            monitor_position.y + monitor_size.height as i32 - window_size.height as i32 - padding,
+        ),
+        "bottom-right" => (
+            monitor_position.x + monitor_size.width as i32 - window_size.width as i32 - padding,
+            monitor_position.y + monitor_size.height as i32 - window_size.height as i32 - padding,
+        ),
+        "center" => (
+            monitor_position.x + (monitor_size.width as i32 - window_size.width as i32) / 2,
+            monitor_position.y + (monitor_size.height as i32 - window_size.height as i32) / 2,
+        ),
+        // "top-right" is the default, matching the overlay's original corner
+        _ => (
+            monitor_position.x + monitor_size.width as i32 - window_size.width as i32 - padding,
+            monitor_position.y + top_padding,
+        ),
+    };
+
     window.set_position(PhysicalPosition::new(x, y))
         .map_err(|e| e.to_string())?;
-    
+
     Ok(())
 }
 
+#[tauri::command]
+async fn position_window_top_right(window: WebviewWindow) -> Result<(), String> {
+    position_window(window, "top-right".to_string()).await
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -216,11 +895,25 @@ pub fn run() {
             load_note,
             toggle_window,
             position_window_top_right,
+            position_window,
             ensure_window_top_level,
             force_window_on_top,
-            debug_window_info
+            debug_window_info,
+            configure_titlebar,
+            toggle_fullscreen,
+            toggle_simple_fullscreen,
+            get_fullscreen_mode,
+            set_window_vibrancy,
+            set_content_protection,
+            set_auto_dim,
+            set_toggle_shortcut
         ])
         .setup(|app| {
+            app.manage(OverlayFullscreenState::default());
+            app.manage(AutoDimState::default());
+            app.manage(VibrancyState::default());
+            app.manage(TitlebarState::default());
+
             // Set activation policy to Accessory to prevent dock icon (macOS only)
             #[cfg(target_os = "macos")]
             app.set_activation_policy(tauri::ActivationPolicy::Accessory);
@@ -252,42 +945,48 @@ pub fn run() {
                 println!("CONVERTED WINDOW TO NSPANEL WITH AGGRESSIVE FOCUS PREVENTION!");
             }
             
-            let window_clone = window.clone();
-            
             // Set window to be visible on all workspaces and always on top
             let _ = window.set_visible_on_all_workspaces(true);
             let _ = window.set_always_on_top(true);
-            
+
             // NSPanel is already configured above, no need for repeated settings
-            
-            // Create a debouncer to prevent multiple rapid shortcut triggers
-            let shortcut_debouncer = Arc::new(AtomicBool::new(false));
-            let debouncer_clone = shortcut_debouncer.clone();
-            
-            // Register global shortcut for Cmd+Shift+` with debouncing
-            app.global_shortcut().on_shortcut("Cmd+Shift+`", move |_, _, _| {
-                // Check if we're already processing a shortcut
-                if debouncer_clone.compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
-                    let window = window_clone.clone();
-                    let debouncer = debouncer_clone.clone();
-                    
-                    tauri::async_runtime::spawn(async move {
-                        // Process the toggle
-                        let _ = toggle_window(window).await;
-                        
-                        // Add a small delay to prevent rapid repeated triggers
-                        tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
-                        
-                        // Reset the debouncer
-                        debouncer.store(false, Ordering::SeqCst);
-                    });
-                }
-            }).expect("Failed to register global shortcut");
-            
+
+            // Load the persisted toggle shortcut (falling back to the default) and register it
+            app.manage(ToggleShortcutState::default());
+            let saved_accelerator = load_toggle_shortcut(&app.handle());
+            {
+                let state = app.state::<ToggleShortcutState>();
+                *state.current_accelerator.lock().expect("toggle shortcut state poisoned") = saved_accelerator.clone();
+            }
+            if let Err(e) = register_toggle_shortcut(&app.handle(), window.clone(), &saved_accelerator) {
+                eprintln!("Failed to register saved toggle shortcut '{}': {}, falling back to default", saved_accelerator, e);
+                let state = app.state::<ToggleShortcutState>();
+                *state.current_accelerator.lock().expect("toggle shortcut state poisoned") = DEFAULT_TOGGLE_SHORTCUT.to_string();
+                register_toggle_shortcut(&app.handle(), window.clone(), DEFAULT_TOGGLE_SHORTCUT)
+                    .expect("Failed to register default global shortcut");
+            }
+
             // Position window in top-right corner
+            let window_for_position = window.clone();
+            tauri::async_runtime::spawn(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+                let _ = position_window_top_right(window_for_position).await;
+            });
+
+            // Configure the custom titlebar so the overlay keeps the frameless look
+            // while still exposing draggable, clickable traffic-light controls
+            let window_for_titlebar = window.clone();
+            tauri::async_runtime::spawn(async move {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+                let _ = configure_titlebar(window_for_titlebar, DEFAULT_TITLEBAR_INSET_X, DEFAULT_TITLEBAR_INSET_Y).await;
+            });
+
+            // Restore the persisted content-protection preference so it survives restarts
+            let app_handle_for_content_protection = app.handle().clone();
+            let content_protection_enabled = load_content_protection(&app_handle_for_content_protection);
             tauri::async_runtime::spawn(async move {
                 tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                let _ = position_window_top_right(window).await;
+                let _ = set_content_protection(window, app_handle_for_content_protection, content_protection_enabled).await;
             });
             
             Ok(())